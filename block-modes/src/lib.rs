@@ -0,0 +1,201 @@
+#![no_std]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![warn(missing_debug_implementations, missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use cipher;
+
+#[cfg(feature = "belt-ctr")]
+pub use belt_ctr;
+#[cfg(feature = "cbc")]
+pub use cbc;
+#[cfg(feature = "cfb8")]
+pub use cfb8;
+#[cfg(feature = "cfb-mode")]
+pub use cfb_mode;
+#[cfg(feature = "ctr")]
+pub use ctr;
+#[cfg(feature = "cts")]
+pub use cts;
+#[cfg(feature = "ecb")]
+pub use ecb;
+#[cfg(feature = "ige")]
+pub use ige;
+#[cfg(feature = "ofb")]
+pub use ofb;
+#[cfg(feature = "pcbc")]
+pub use pcbc;
+
+#[cfg(any(feature = "aes", feature = "sm4"))]
+pub mod registry;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use cipher::{
+    inout::InOutBuf, typenum::Unsigned, BlockModeDecrypt, BlockModeEncrypt, StreamCipher,
+};
+#[cfg(feature = "alloc")]
+use cipher::{InvalidLength, KeyIvInit};
+use core::fmt;
+
+/// Error type returned by [`DynBlockModeEncrypt`] and [`DynBlockModeDecrypt`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Buffer length is not multiple of mode's block size.
+    InvalidBufferLength,
+    /// End of the stream cipher keystream would be reached while processing
+    /// the buffer.
+    KeystreamExhausted,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidBufferLength => "buffer length is not multiple of block size",
+            Error::KeystreamExhausted => "end of keystream reached",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Object-safe encryption functionality of block modes.
+///
+/// Implemented for all types which implement [`BlockModeEncrypt`].
+pub trait DynBlockModeEncrypt {
+    /// Get block size of the underlying mode in bytes.
+    fn block_size(&self) -> usize;
+
+    /// Encrypt data in-place.
+    ///
+    /// Length of `buf` must be multiple of [`block_size`][Self::block_size],
+    /// otherwise the buffer is left untouched and an error is returned.
+    fn encrypt(&mut self, buf: &mut [u8]) -> Result<(), Error>;
+}
+
+/// Object-safe decryption functionality of block modes.
+///
+/// Implemented for all types which implement [`BlockModeDecrypt`].
+pub trait DynBlockModeDecrypt {
+    /// Get block size of the underlying mode in bytes.
+    fn block_size(&self) -> usize;
+
+    /// Decrypt data in-place.
+    ///
+    /// Length of `buf` must be multiple of [`block_size`][Self::block_size],
+    /// otherwise the buffer is left untouched and an error is returned.
+    fn decrypt(&mut self, buf: &mut [u8]) -> Result<(), Error>;
+}
+
+impl<T: BlockModeEncrypt> DynBlockModeEncrypt for T {
+    #[inline]
+    fn block_size(&self) -> usize {
+        T::BlockSize::USIZE
+    }
+
+    #[inline]
+    fn encrypt(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let (blocks, tail) = InOutBuf::from(buf).into_chunks();
+        if !tail.is_empty() {
+            return Err(Error::InvalidBufferLength);
+        }
+        self.encrypt_blocks_inout(blocks);
+        Ok(())
+    }
+}
+
+impl<T: BlockModeDecrypt> DynBlockModeDecrypt for T {
+    #[inline]
+    fn block_size(&self) -> usize {
+        T::BlockSize::USIZE
+    }
+
+    #[inline]
+    fn decrypt(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let (blocks, tail) = InOutBuf::from(buf).into_chunks();
+        if !tail.is_empty() {
+            return Err(Error::InvalidBufferLength);
+        }
+        self.decrypt_blocks_inout(blocks);
+        Ok(())
+    }
+}
+
+/// Adapter which exposes a stream cipher, e.g. the CTR or OFB mode, through
+/// [`DynBlockModeEncrypt`] and [`DynBlockModeDecrypt`].
+///
+/// The reported block size is one byte, i.e. buffers of any length are
+/// accepted. Keystream position carries over between calls. If the end of
+/// the keystream would be reached, the buffer is left untouched and
+/// [`Error::KeystreamExhausted`] is returned.
+#[derive(Clone)]
+pub struct StreamMode<S>(pub S);
+
+impl<S> fmt::Debug for StreamMode<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("block_modes::StreamMode { ... }")
+    }
+}
+
+impl<S: StreamCipher> DynBlockModeEncrypt for StreamMode<S> {
+    #[inline]
+    fn block_size(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn encrypt(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.0
+            .try_apply_keystream(buf)
+            .map_err(|_| Error::KeystreamExhausted)
+    }
+}
+
+impl<S: StreamCipher> DynBlockModeDecrypt for StreamMode<S> {
+    #[inline]
+    fn block_size(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn decrypt(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.0
+            .try_apply_keystream(buf)
+            .map_err(|_| Error::KeystreamExhausted)
+    }
+}
+
+/// Initialize mode `M` from key and IV slices and box it as [`DynBlockModeEncrypt`].
+#[cfg(feature = "alloc")]
+pub fn boxed_encryptor<M>(
+    key: &[u8],
+    iv: &[u8],
+) -> Result<Box<dyn DynBlockModeEncrypt>, InvalidLength>
+where
+    M: BlockModeEncrypt + KeyIvInit + 'static,
+{
+    let mode = M::new_from_slices(key, iv)?;
+    Ok(Box::new(mode))
+}
+
+/// Initialize mode `M` from key and IV slices and box it as [`DynBlockModeDecrypt`].
+#[cfg(feature = "alloc")]
+pub fn boxed_decryptor<M>(
+    key: &[u8],
+    iv: &[u8],
+) -> Result<Box<dyn DynBlockModeDecrypt>, InvalidLength>
+where
+    M: BlockModeDecrypt + KeyIvInit + 'static,
+{
+    let mode = M::new_from_slices(key, iv)?;
+    Ok(Box::new(mode))
+}
@@ -0,0 +1,208 @@
+//! Runtime registry of block modes keyed by algorithm name.
+//!
+//! Names follow the `<cipher>-<key size>-<mode>` scheme used by OpenSSL,
+//! e.g. `"aes-256-cbc"` or `"sm4-cbc"`. Only ciphers and modes enabled via
+//! crate features are available.
+//!
+//! | Feature | Cipher | Modes |
+//! |---------|--------|-------|
+//! | `aes` | `aes-128`, `aes-192`, `aes-256` | `cbc`, `cfb`, `cfb8`, `ctr`, `ecb`, `ige`, `ofb`, `pcbc` |
+//! | `sm4` | `sm4` | `cbc`, `cfb`, `cfb8`, `ctr`, `ecb`, `ige`, `ofb`, `pcbc` |
+//!
+//! As in OpenSSL, `cfb`, `cfb8`, `ctr` and `ofb` are processed as streams and
+//! accept buffers of any length (their block size is reported as one byte),
+//! while `cbc`, `ecb`, `ige` and `pcbc` require buffer length to be multiple
+//! of the block size. `ctr` uses a 128-bit big-endian counter and `ecb`
+//! expects an empty IV.
+
+use crate::{DynBlockModeDecrypt, DynBlockModeEncrypt};
+use alloc::boxed::Box;
+use cipher::{
+    consts::U16, BlockCipherDecrypt, BlockCipherEncrypt, BlockSizeUser, InvalidLength, KeyInit,
+};
+use core::fmt;
+
+#[cfg(any(feature = "cbc", feature = "cfb8", feature = "ige", feature = "pcbc"))]
+use crate::{boxed_decryptor, boxed_encryptor};
+#[cfg(any(feature = "ctr", feature = "ofb"))]
+use crate::StreamMode;
+#[cfg(any(feature = "cfb-mode", feature = "ctr", feature = "ofb"))]
+use cipher::KeyIvInit;
+
+/// Error returned by the registry lookup functions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Algorithm name is not known or its mode is not enabled.
+    UnknownAlgorithm,
+    /// Key or IV has invalid length for the requested algorithm.
+    InvalidLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::UnknownAlgorithm => "unknown or disabled algorithm",
+            Error::InvalidLength => "invalid key or IV length",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<InvalidLength> for Error {
+    fn from(_: InvalidLength) -> Self {
+        Error::InvalidLength
+    }
+}
+
+/// Create boxed encryptor for the algorithm with the given `name`.
+pub fn encryptor(name: &str, key: &[u8], iv: &[u8]) -> Result<Box<dyn DynBlockModeEncrypt>, Error> {
+    let (cipher, mode) = name.rsplit_once('-').ok_or(Error::UnknownAlgorithm)?;
+    match cipher {
+        #[cfg(feature = "aes")]
+        "aes-128" => encryptor_for::<aes::Aes128>(mode, key, iv),
+        #[cfg(feature = "aes")]
+        "aes-192" => encryptor_for::<aes::Aes192>(mode, key, iv),
+        #[cfg(feature = "aes")]
+        "aes-256" => encryptor_for::<aes::Aes256>(mode, key, iv),
+        #[cfg(feature = "sm4")]
+        "sm4" => encryptor_for::<sm4::Sm4>(mode, key, iv),
+        _ => Err(Error::UnknownAlgorithm),
+    }
+}
+
+/// Create boxed decryptor for the algorithm with the given `name`.
+pub fn decryptor(name: &str, key: &[u8], iv: &[u8]) -> Result<Box<dyn DynBlockModeDecrypt>, Error> {
+    let (cipher, mode) = name.rsplit_once('-').ok_or(Error::UnknownAlgorithm)?;
+    match cipher {
+        #[cfg(feature = "aes")]
+        "aes-128" => decryptor_for::<aes::Aes128>(mode, key, iv),
+        #[cfg(feature = "aes")]
+        "aes-192" => decryptor_for::<aes::Aes192>(mode, key, iv),
+        #[cfg(feature = "aes")]
+        "aes-256" => decryptor_for::<aes::Aes256>(mode, key, iv),
+        #[cfg(feature = "sm4")]
+        "sm4" => decryptor_for::<sm4::Sm4>(mode, key, iv),
+        _ => Err(Error::UnknownAlgorithm),
+    }
+}
+
+fn encryptor_for<C>(
+    mode: &str,
+    key: &[u8],
+    iv: &[u8],
+) -> Result<Box<dyn DynBlockModeEncrypt>, Error>
+where
+    C: BlockCipherEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + 'static,
+{
+    // `key` and `iv` are unused if no mode features are enabled
+    let _ = (key, iv);
+    match mode {
+        #[cfg(feature = "cbc")]
+        "cbc" => Ok(boxed_encryptor::<cbc::Encryptor<C>>(key, iv)?),
+        #[cfg(feature = "cfb-mode")]
+        "cfb" => Ok(Box::new(CfbEncryptor::<C>(KeyIvInit::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "cfb8")]
+        "cfb8" => Ok(boxed_encryptor::<cfb8::Encryptor<C>>(key, iv)?),
+        #[cfg(feature = "ctr")]
+        "ctr" => Ok(Box::new(StreamMode(ctr::Ctr128BE::<C>::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "ecb")]
+        "ecb" => Ok(Box::new(ecb::Encryptor::<C>::new_from_slice(no_iv(
+            key, iv,
+        )?)?)),
+        #[cfg(feature = "ige")]
+        "ige" => Ok(boxed_encryptor::<ige::Encryptor<C>>(key, iv)?),
+        #[cfg(feature = "ofb")]
+        "ofb" => Ok(Box::new(StreamMode(ofb::Ofb::<C>::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "pcbc")]
+        "pcbc" => Ok(boxed_encryptor::<pcbc::Encryptor<C>>(key, iv)?),
+        _ => Err(Error::UnknownAlgorithm),
+    }
+}
+
+fn decryptor_for<C>(
+    mode: &str,
+    key: &[u8],
+    iv: &[u8],
+) -> Result<Box<dyn DynBlockModeDecrypt>, Error>
+where
+    C: BlockCipherEncrypt + BlockCipherDecrypt + BlockSizeUser<BlockSize = U16> + KeyInit + 'static,
+{
+    // `key` and `iv` are unused if no mode features are enabled
+    let _ = (key, iv);
+    match mode {
+        #[cfg(feature = "cbc")]
+        "cbc" => Ok(boxed_decryptor::<cbc::Decryptor<C>>(key, iv)?),
+        #[cfg(feature = "cfb-mode")]
+        "cfb" => Ok(Box::new(CfbDecryptor::<C>(KeyIvInit::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "cfb8")]
+        "cfb8" => Ok(boxed_decryptor::<cfb8::Decryptor<C>>(key, iv)?),
+        #[cfg(feature = "ctr")]
+        "ctr" => Ok(Box::new(StreamMode(ctr::Ctr128BE::<C>::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "ecb")]
+        "ecb" => Ok(Box::new(ecb::Decryptor::<C>::new_from_slice(no_iv(
+            key, iv,
+        )?)?)),
+        #[cfg(feature = "ige")]
+        "ige" => Ok(boxed_decryptor::<ige::Decryptor<C>>(key, iv)?),
+        #[cfg(feature = "ofb")]
+        "ofb" => Ok(Box::new(StreamMode(ofb::Ofb::<C>::new_from_slices(
+            key, iv,
+        )?))),
+        #[cfg(feature = "pcbc")]
+        "pcbc" => Ok(boxed_decryptor::<pcbc::Decryptor<C>>(key, iv)?),
+        _ => Err(Error::UnknownAlgorithm),
+    }
+}
+
+/// Check that no IV was provided for modes which do not use it and return `key`.
+#[cfg(feature = "ecb")]
+fn no_iv<'a>(key: &'a [u8], iv: &[u8]) -> Result<&'a [u8], Error> {
+    if iv.is_empty() {
+        Ok(key)
+    } else {
+        Err(Error::InvalidLength)
+    }
+}
+
+/// Full-block CFB encryptor which accepts buffers of any length.
+#[cfg(feature = "cfb-mode")]
+struct CfbEncryptor<C: BlockCipherEncrypt>(cfb_mode::BufEncryptor<C>);
+
+#[cfg(feature = "cfb-mode")]
+impl<C: BlockCipherEncrypt> DynBlockModeEncrypt for CfbEncryptor<C> {
+    fn block_size(&self) -> usize {
+        1
+    }
+
+    fn encrypt(&mut self, buf: &mut [u8]) -> Result<(), crate::Error> {
+        self.0.encrypt(buf);
+        Ok(())
+    }
+}
+
+/// Full-block CFB decryptor which accepts buffers of any length.
+#[cfg(feature = "cfb-mode")]
+struct CfbDecryptor<C: BlockCipherEncrypt>(cfb_mode::BufDecryptor<C>);
+
+#[cfg(feature = "cfb-mode")]
+impl<C: BlockCipherEncrypt> DynBlockModeDecrypt for CfbDecryptor<C> {
+    fn block_size(&self) -> usize {
+        1
+    }
+
+    fn decrypt(&mut self, buf: &mut [u8]) -> Result<(), crate::Error> {
+        self.0.decrypt(buf);
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+use aes::Aes128;
+use block_modes::{boxed_decryptor, boxed_encryptor, Error};
+use cipher::{inout::InOutBuf, BlockModeEncrypt, KeyIvInit};
+
+const KEY: [u8; 16] = [0x42; 16];
+const IV: [u8; 16] = [0x24; 16];
+
+#[test]
+fn dyn_matches_generic() {
+    let mut pt = [0u8; 64];
+    for (i, b) in pt.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mut expected = pt;
+    let (blocks, _) = InOutBuf::from(&mut expected[..]).into_chunks();
+    cbc::Encryptor::<Aes128>::new(&KEY.into(), &IV.into()).encrypt_blocks_inout(blocks);
+
+    let mut enc = boxed_encryptor::<cbc::Encryptor<Aes128>>(&KEY, &IV).unwrap();
+    assert_eq!(enc.block_size(), 16);
+    let mut buf = pt;
+    // state must carry over between calls
+    enc.encrypt(&mut buf[..32]).unwrap();
+    enc.encrypt(&mut buf[32..]).unwrap();
+    assert_eq!(buf, expected);
+
+    let mut dec = boxed_decryptor::<cbc::Decryptor<Aes128>>(&KEY, &IV).unwrap();
+    assert_eq!(dec.block_size(), 16);
+    dec.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+#[test]
+fn dyn_rejects_partial_blocks() {
+    let mut enc = boxed_encryptor::<cbc::Encryptor<Aes128>>(&KEY, &IV).unwrap();
+    let mut buf = [0u8; 20];
+    assert_eq!(enc.encrypt(&mut buf), Err(Error::InvalidBufferLength));
+    assert_eq!(buf, [0u8; 20]);
+
+    let mut dec = boxed_decryptor::<cbc::Decryptor<Aes128>>(&KEY, &IV).unwrap();
+    let err: Box<dyn std::error::Error> = dec.decrypt(&mut buf[..15]).unwrap_err().into();
+    assert_eq!(
+        err.to_string(),
+        "buffer length is not multiple of block size"
+    );
+}
+
+#[cfg(feature = "ctr")]
+#[test]
+fn stream_mode_keystream_exhausted() {
+    use block_modes::{ctr, DynBlockModeEncrypt, StreamMode};
+    use cipher::StreamCipherSeek;
+
+    let mut cipher = ctr::Ctr32BE::<Aes128>::new(&KEY.into(), &IV.into());
+    // move to the last block before the 32-bit counter wraps
+    cipher.seek(16 * (u64::from(u32::MAX) - 1));
+    let mut enc = StreamMode(cipher);
+    let mut buf = [0u8; 48];
+    assert_eq!(enc.encrypt(&mut buf), Err(Error::KeystreamExhausted));
+    assert_eq!(buf, [0u8; 48]);
+}
+
+#[test]
+fn boxed_invalid_key_length() {
+    assert!(boxed_encryptor::<cbc::Encryptor<Aes128>>(&KEY[..15], &IV).is_err());
+    assert!(boxed_decryptor::<cbc::Decryptor<Aes128>>(&KEY, &IV[..8]).is_err());
+}
+
+#[cfg(all(feature = "aes", feature = "cbc"))]
+#[test]
+fn registry_matches_generic() {
+    use block_modes::registry;
+
+    let key = [0x42; 24];
+    let mut pt = [0u8; 48];
+    for (i, b) in pt.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mut expected = pt;
+    let (blocks, _) = InOutBuf::from(&mut expected[..]).into_chunks();
+    cbc::Encryptor::<aes::Aes192>::new(&key.into(), &IV.into()).encrypt_blocks_inout(blocks);
+
+    let mut buf = pt;
+    let mut enc = registry::encryptor("aes-192-cbc", &key, &IV).unwrap();
+    enc.encrypt(&mut buf).unwrap();
+    assert_eq!(buf, expected);
+
+    let mut dec = registry::decryptor("aes-192-cbc", &key, &IV).unwrap();
+    dec.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+#[cfg(all(
+    feature = "aes",
+    feature = "cbc",
+    feature = "cfb-mode",
+    feature = "cfb8",
+    feature = "ctr",
+    feature = "ecb",
+    feature = "ige",
+    feature = "ofb",
+    feature = "pcbc"
+))]
+#[test]
+fn registry_roundtrip() {
+    use block_modes::registry;
+
+    let names = [
+        "aes-128-cbc",
+        "aes-128-cfb",
+        "aes-128-cfb8",
+        "aes-128-ctr",
+        "aes-128-ofb",
+        "aes-128-pcbc",
+        "aes-256-cbc",
+        "aes-256-cfb",
+        "aes-256-cfb8",
+        "aes-256-ctr",
+        "aes-256-ofb",
+        "aes-256-pcbc",
+    ];
+    let pt = [0x5a; 64];
+    for name in names {
+        let key_len = if name.starts_with("aes-128") { 16 } else { 32 };
+        let key = &[0x42; 32][..key_len];
+
+        let mut buf = pt;
+        registry::encryptor(name, key, &IV)
+            .unwrap()
+            .encrypt(&mut buf)
+            .unwrap();
+        assert_ne!(buf, pt, "{name}");
+        registry::decryptor(name, key, &IV)
+            .unwrap()
+            .decrypt(&mut buf)
+            .unwrap();
+        assert_eq!(buf, pt, "{name}");
+    }
+
+    // IGE uses IV twice as long as block size
+    let mut buf = pt;
+    let iv = [0x24; 32];
+    registry::encryptor("aes-128-ige", &KEY, &iv)
+        .unwrap()
+        .encrypt(&mut buf)
+        .unwrap();
+    registry::decryptor("aes-128-ige", &KEY, &iv)
+        .unwrap()
+        .decrypt(&mut buf)
+        .unwrap();
+    assert_eq!(buf, pt);
+
+    // ECB does not use IV
+    let mut buf = pt;
+    registry::encryptor("aes-128-ecb", &KEY, &[])
+        .unwrap()
+        .encrypt(&mut buf)
+        .unwrap();
+    assert_eq!(buf[..16], buf[16..32]);
+    registry::decryptor("aes-128-ecb", &KEY, &[])
+        .unwrap()
+        .decrypt(&mut buf)
+        .unwrap();
+    assert_eq!(buf, pt);
+}
+
+#[cfg(all(
+    feature = "aes",
+    feature = "cfb-mode",
+    feature = "ctr",
+    feature = "ofb"
+))]
+#[test]
+fn registry_stream_modes() {
+    use block_modes::{cfb_mode, ctr, ofb, registry};
+    use cipher::StreamCipher;
+
+    let mut pt = [0u8; 37];
+    for (i, b) in pt.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mut ctr_ct = pt;
+    ctr::Ctr128BE::<Aes128>::new(&KEY.into(), &IV.into()).apply_keystream(&mut ctr_ct);
+    let mut ofb_ct = pt;
+    ofb::Ofb::<Aes128>::new(&KEY.into(), &IV.into()).apply_keystream(&mut ofb_ct);
+    let mut cfb_ct = pt;
+    cfb_mode::BufEncryptor::<Aes128>::new(&KEY.into(), &IV.into()).encrypt(&mut cfb_ct);
+
+    for (name, expected) in [
+        ("aes-128-ctr", ctr_ct),
+        ("aes-128-ofb", ofb_ct),
+        ("aes-128-cfb", cfb_ct),
+    ] {
+        let mut enc = registry::encryptor(name, &KEY, &IV).unwrap();
+        assert_eq!(enc.block_size(), 1, "{name}");
+        let mut buf = pt;
+        // partial blocks must carry over between calls
+        let (a, b) = buf.split_at_mut(5);
+        enc.encrypt(a).unwrap();
+        enc.encrypt(b).unwrap();
+        assert_eq!(buf, expected, "{name}");
+
+        let mut dec = registry::decryptor(name, &KEY, &IV).unwrap();
+        let (a, b) = buf.split_at_mut(21);
+        dec.decrypt(a).unwrap();
+        dec.decrypt(b).unwrap();
+        assert_eq!(buf, pt, "{name}");
+    }
+}
+
+#[cfg(all(
+    feature = "aes",
+    feature = "cbc",
+    feature = "ecb",
+    feature = "ige"
+))]
+#[test]
+fn registry_errors() {
+    use block_modes::registry::{self, Error};
+
+    assert_eq!(
+        registry::encryptor("aes-128-xyz", &KEY, &IV).err(),
+        Some(Error::UnknownAlgorithm),
+    );
+    assert_eq!(
+        registry::decryptor("des-cbc", &KEY, &IV).err(),
+        Some(Error::UnknownAlgorithm),
+    );
+    assert_eq!(
+        registry::encryptor("aes", &KEY, &IV).err(),
+        Some(Error::UnknownAlgorithm),
+    );
+    assert_eq!(
+        registry::encryptor("aes-256-cbc", &KEY, &IV).err(),
+        Some(Error::InvalidLength),
+    );
+    assert_eq!(
+        registry::decryptor("aes-128-ige", &KEY, &IV).err(),
+        Some(Error::InvalidLength),
+    );
+    assert_eq!(
+        registry::encryptor("aes-128-ecb", &KEY, &IV).err(),
+        Some(Error::InvalidLength),
+    );
+
+    let err: Box<dyn std::error::Error> = registry::encryptor("aes-128-xyz", &KEY, &IV)
+        .err()
+        .unwrap()
+        .into();
+    assert_eq!(err.to_string(), "unknown or disabled algorithm");
+}
@@ -0,0 +1,47 @@
+#![cfg(all(feature = "sm4", feature = "cbc", feature = "ctr", feature = "ecb"))]
+
+use block_modes::registry;
+use hex_literal::hex;
+
+const KEY: [u8; 16] = hex!("0123456789abcdeffedcba9876543210");
+
+/// Example 1 from GB/T 32907-2016
+#[test]
+fn registry_sm4_ecb() {
+    let mut buf = KEY;
+    registry::encryptor("sm4-ecb", &KEY, &[])
+        .unwrap()
+        .encrypt(&mut buf)
+        .unwrap();
+    assert_eq!(buf, hex!("681edf34d206965e86b3e94f536e4246"));
+
+    registry::decryptor("sm4-ecb", &KEY, &[])
+        .unwrap()
+        .decrypt(&mut buf)
+        .unwrap();
+    assert_eq!(buf, KEY);
+}
+
+#[test]
+fn registry_sm4_roundtrip() {
+    let iv = [0x24; 16];
+    let pt = [0x5a; 48];
+    for name in ["sm4-cbc", "sm4-ctr"] {
+        let mut buf = pt;
+        registry::encryptor(name, &KEY, &iv)
+            .unwrap()
+            .encrypt(&mut buf)
+            .unwrap();
+        assert_ne!(buf, pt, "{name}");
+        registry::decryptor(name, &KEY, &iv)
+            .unwrap()
+            .decrypt(&mut buf)
+            .unwrap();
+        assert_eq!(buf, pt, "{name}");
+    }
+
+    assert_eq!(
+        registry::encryptor("sm4-128-cbc", &KEY, &iv).err(),
+        Some(registry::Error::UnknownAlgorithm),
+    );
+}
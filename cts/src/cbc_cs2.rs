@@ -1,12 +1,13 @@
-use crate::{cbc_dec, cbc_enc, xor, Decrypt, Encrypt, Error};
+use crate::{cbc_dec, cbc_enc, check_len, xor, Decrypt, Encrypt, Error};
 use cipher::{
     array::Array,
     crypto_common::{BlockSizes, InnerUser},
     inout::InOutBuf,
-    typenum::Unsigned,
-    Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt, BlockCipherEncBackend,
-    BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InnerIvInit, IvSizeUser,
+    AlgorithmName, Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt,
+    BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InnerIvInit,
+    IvSizeUser,
 };
+use core::fmt;
 
 /// The CBC-CS-2 ciphertext stealing mode.
 #[derive(Clone)]
@@ -32,11 +33,25 @@ impl<C: BlockSizeUser> InnerIvInit for CbcCs2<C> {
     }
 }
 
+impl<C: BlockSizeUser + AlgorithmName> AlgorithmName for CbcCs2<C> {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::CbcCs2<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C: BlockSizeUser + AlgorithmName> fmt::Debug for CbcCs2<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::CbcCs2<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
 impl<C: BlockCipherEncrypt> Encrypt for CbcCs2<C> {
     fn encrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
-        if buf.len() < C::BlockSize::USIZE {
-            return Err(Error);
-        }
+        check_len::<C::BlockSize>(buf.len())?;
         let Self { cipher, iv } = self;
         cipher.encrypt_with_backend(Closure { iv, buf });
         Ok(())
@@ -45,9 +60,7 @@ impl<C: BlockCipherEncrypt> Encrypt for CbcCs2<C> {
 
 impl<C: BlockCipherDecrypt> Decrypt for CbcCs2<C> {
     fn decrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
-        if buf.len() < C::BlockSize::USIZE {
-            return Err(Error);
-        }
+        check_len::<C::BlockSize>(buf.len())?;
         let Self { cipher, iv } = self;
         cipher.decrypt_with_backend(Closure { iv, buf });
         Ok(())
@@ -0,0 +1,114 @@
+use crate::{cbc_dec, cbc_enc, Decrypt, Encrypt, Error};
+use cipher::{
+    array::Array,
+    crypto_common::{BlockSizes, InnerUser},
+    inout::InOutBuf,
+    AlgorithmName, Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt,
+    BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InnerIvInit,
+    IvSizeUser,
+};
+use core::fmt;
+
+/// The CBC mode with residual block termination (RBT).
+///
+/// Full blocks are processed in the CBC mode, while the trailing partial block
+/// is XORed with encryption of the last ciphertext block (or IV if message is
+/// shorter than one block). Unlike the ciphertext stealing modes, RBT supports
+/// messages of any length.
+///
+/// Note that decryption requires both block cipher encryption and decryption.
+#[derive(Clone)]
+pub struct CbcRbt<C: BlockSizeUser> {
+    cipher: C,
+    iv: Block<C>,
+}
+
+impl<C: BlockSizeUser> InnerUser for CbcRbt<C> {
+    type Inner = C;
+}
+
+impl<C: BlockSizeUser> IvSizeUser for CbcRbt<C> {
+    type IvSize = C::BlockSize;
+}
+
+impl<C: BlockSizeUser> InnerIvInit for CbcRbt<C> {
+    fn inner_iv_init(cipher: Self::Inner, iv: &cipher::Iv<Self>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+        }
+    }
+}
+
+impl<C: BlockSizeUser + AlgorithmName> AlgorithmName for CbcRbt<C> {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::CbcRbt<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C: BlockSizeUser + AlgorithmName> fmt::Debug for CbcRbt<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::CbcRbt<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+impl<C: BlockCipherEncrypt> Encrypt for CbcRbt<C> {
+    fn encrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
+        let Self { cipher, mut iv } = self;
+        let (blocks, mut tail) = buf.into_chunks();
+        cipher.encrypt_with_backend(Closure {
+            iv: &mut iv,
+            blocks,
+        });
+
+        if !tail.is_empty() {
+            cipher.encrypt_block(&mut iv);
+            tail.xor_in2out(&iv[..tail.len()]);
+        }
+        Ok(())
+    }
+}
+
+impl<C: BlockCipherEncrypt + BlockCipherDecrypt> Decrypt for CbcRbt<C> {
+    fn decrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
+        let Self { cipher, mut iv } = self;
+        let (blocks, mut tail) = buf.into_chunks();
+        cipher.decrypt_with_backend(Closure {
+            iv: &mut iv,
+            blocks,
+        });
+
+        if !tail.is_empty() {
+            cipher.encrypt_block(&mut iv);
+            tail.xor_in2out(&iv[..tail.len()]);
+        }
+        Ok(())
+    }
+}
+
+struct Closure<'a, BS: BlockSizes> {
+    iv: &'a mut Array<u8, BS>,
+    blocks: InOutBuf<'a, 'a, Array<u8, BS>>,
+}
+
+impl<BS: BlockSizes> BlockSizeUser for Closure<'_, BS> {
+    type BlockSize = BS;
+}
+
+impl<BS: BlockSizes> BlockCipherEncClosure for Closure<'_, BS> {
+    fn call<B: BlockCipherEncBackend<BlockSize = BS>>(self, cipher: &B) {
+        let Self { iv, blocks } = self;
+        cbc_enc(cipher, iv, blocks);
+    }
+}
+
+impl<BS: BlockSizes> BlockCipherDecClosure for Closure<'_, BS> {
+    fn call<B: BlockCipherDecBackend<BlockSize = BS>>(self, cipher: &B) {
+        let Self { iv, blocks } = self;
+        cbc_dec(cipher, iv, blocks);
+    }
+}
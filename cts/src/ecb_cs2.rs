@@ -1,12 +1,11 @@
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData};
 
-use crate::{ecb_dec, ecb_enc, Decrypt, Encrypt, Error};
+use crate::{check_len, ecb_dec, ecb_enc, Decrypt, Encrypt, Error};
 use cipher::{
     crypto_common::{BlockSizes, InnerInit, InnerUser},
     inout::InOutBuf,
-    typenum::Unsigned,
-    Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt, BlockCipherEncBackend,
-    BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, IvSizeUser,
+    AlgorithmName, Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt,
+    BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, IvSizeUser,
 };
 
 /// The ECB-CS-2 ciphertext stealing mode.
@@ -29,11 +28,25 @@ impl<C: BlockSizeUser> InnerInit for EcbCs2<C> {
     }
 }
 
+impl<C: BlockSizeUser + AlgorithmName> AlgorithmName for EcbCs2<C> {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::EcbCs2<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C: BlockSizeUser + AlgorithmName> fmt::Debug for EcbCs2<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cts::EcbCs2<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
 impl<C: BlockCipherEncrypt> Encrypt for EcbCs2<C> {
     fn encrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
-        if buf.len() < C::BlockSize::USIZE {
-            return Err(Error);
-        }
+        check_len::<C::BlockSize>(buf.len())?;
         self.cipher.encrypt_with_backend(Closure {
             buf,
             _pd: PhantomData,
@@ -44,9 +57,7 @@ impl<C: BlockCipherEncrypt> Encrypt for EcbCs2<C> {
 
 impl<C: BlockCipherDecrypt> Decrypt for EcbCs2<C> {
     fn decrypt_inout(self, buf: InOutBuf<'_, '_, u8>) -> Result<(), Error> {
-        if buf.len() < C::BlockSize::USIZE {
-            return Err(Error);
-        }
+        check_len::<C::BlockSize>(buf.len())?;
         self.cipher.decrypt_with_backend(Closure {
             buf,
             _pd: PhantomData,
@@ -6,7 +6,10 @@
 )]
 #![deny(unsafe_code)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
-#![warn(missing_docs, rust_2018_idioms)]
+#![warn(missing_debug_implementations, missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub use cipher;
 
@@ -15,6 +18,7 @@ pub use cipher::{KeyInit, KeyIvInit};
 mod cbc_cs1;
 mod cbc_cs2;
 mod cbc_cs3;
+mod cbc_rbt;
 mod ecb_cs1;
 mod ecb_cs2;
 mod ecb_cs3;
@@ -22,6 +26,7 @@ mod ecb_cs3;
 pub use cbc_cs1::CbcCs1;
 pub use cbc_cs2::CbcCs2;
 pub use cbc_cs3::CbcCs3;
+pub use cbc_rbt::CbcRbt;
 pub use ecb_cs1::EcbCs1;
 pub use ecb_cs2::EcbCs2;
 pub use ecb_cs3::EcbCs3;
@@ -32,10 +37,43 @@ use cipher::{
     typenum::Unsigned,
     Block, BlockCipherDecBackend, BlockCipherEncBackend,
 };
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Error type returned by CTS modes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Message is smaller than cipher's block size.
+    TooShort {
+        /// Minimum message length supported by the mode, i.e. the block size.
+        min_len: usize,
+        /// Length of the provided message.
+        actual_len: usize,
+    },
+    /// Input and output buffers passed to a buffer-to-buffer method have
+    /// different lengths.
+    LengthMismatch,
+}
 
-/// Error which indicates that message is smaller than cipher's block size.
-#[derive(Copy, Clone, Debug)]
-pub struct Error;
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TooShort {
+                min_len,
+                actual_len,
+            } => write!(
+                f,
+                "message is {actual_len} bytes long, but at least {min_len} bytes are required"
+            ),
+            Error::LengthMismatch => f.write_str("input and output buffers have different lengths"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
 
 /// Encryption functionality of CTS modes.
 pub trait Encrypt: Sized {
@@ -50,9 +88,17 @@ pub trait Encrypt: Sized {
     /// Encrypt data buffer-to-buffer.
     fn encrypt_b2b(self, in_buf: &[u8], out_buf: &mut [u8]) -> Result<(), Error> {
         InOutBuf::new(in_buf, out_buf)
-            .map_err(|NotEqualError| Error)
+            .map_err(|NotEqualError| Error::LengthMismatch)
             .and_then(|buf| self.encrypt_inout(buf))
     }
+
+    /// Encrypt data into a newly allocated vector.
+    #[cfg(feature = "alloc")]
+    fn encrypt_vec(self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut buf = msg.to_vec();
+        self.encrypt(&mut buf)?;
+        Ok(buf)
+    }
 }
 
 /// Decryption functionality of CTS modes.
@@ -68,9 +114,17 @@ pub trait Decrypt: Sized {
     /// Decrypt data buffer-to-buffer.
     fn decrypt_b2b(self, in_buf: &[u8], out_buf: &mut [u8]) -> Result<(), Error> {
         InOutBuf::new(in_buf, out_buf)
-            .map_err(|NotEqualError| Error)
+            .map_err(|NotEqualError| Error::LengthMismatch)
             .and_then(|buf| self.decrypt_inout(buf))
     }
+
+    /// Decrypt data into a newly allocated vector.
+    #[cfg(feature = "alloc")]
+    fn decrypt_vec(self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut buf = msg.to_vec();
+        self.decrypt(&mut buf)?;
+        Ok(buf)
+    }
 }
 
 fn ecb_enc<B: BlockCipherEncBackend>(cipher: &B, mut blocks: InOutBuf<'_, '_, Block<B>>) {
@@ -147,6 +201,18 @@ fn cbc_dec<B: BlockCipherDecBackend>(
     }
 }
 
+#[inline(always)]
+fn check_len<BS: Unsigned>(len: usize) -> Result<(), Error> {
+    if len < BS::USIZE {
+        Err(Error::TooShort {
+            min_len: BS::USIZE,
+            actual_len: len,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 #[inline(always)]
 fn xor<N: ArraySize>(out: &mut Array<u8, N>, buf: &Array<u8, N>) {
     for (a, b) in out.iter_mut().zip(buf) {
@@ -89,3 +89,124 @@ macro_rules! impl_ecb_roundtrip {
 impl_ecb_roundtrip!(aes128_ecb_cs1_roundtrip, EcbCs1);
 impl_ecb_roundtrip!(aes128_ecb_cs2_roundtrip, EcbCs2);
 impl_ecb_roundtrip!(aes128_ecb_cs3_roundtrip, EcbCs3);
+
+#[test]
+fn aes128_short_message_error() {
+    use cts::Error;
+
+    let cipher = Aes128::new(&KEY.into());
+    let mut buf = [0u8; 15];
+
+    let res = cts::CbcCs3::inner_iv_init(&cipher, &IV.into()).encrypt(&mut buf);
+    assert_eq!(
+        res,
+        Err(Error::TooShort {
+            min_len: 16,
+            actual_len: 15
+        })
+    );
+    let res = cts::EcbCs1::inner_init(&cipher).decrypt(&mut buf[..3]);
+    assert_eq!(
+        res,
+        Err(Error::TooShort {
+            min_len: 16,
+            actual_len: 3
+        })
+    );
+
+    let mut out = [0u8; 16];
+    let res = cts::CbcCs1::inner_iv_init(&cipher, &IV.into()).encrypt_b2b(&MSG[..17], &mut out);
+    assert_eq!(res, Err(Error::LengthMismatch));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn aes128_vec_roundtrip() {
+    let cipher = Aes128::new(&KEY.into());
+    for i in 16..MSG.len() {
+        let orig_pt = &MSG[..i];
+
+        let mut ct = orig_pt.to_vec();
+        cts::CbcCs2::inner_iv_init(&cipher, &IV.into())
+            .encrypt(&mut ct)
+            .unwrap();
+        let res = cts::CbcCs2::inner_iv_init(&cipher, &IV.into())
+            .encrypt_vec(orig_pt)
+            .unwrap();
+        assert_eq!(res, ct);
+
+        let pt = cts::CbcCs2::inner_iv_init(&cipher, &IV.into())
+            .decrypt_vec(&ct)
+            .unwrap();
+        assert_eq!(pt, orig_pt);
+    }
+
+    assert!(cts::EcbCs3::inner_init(&cipher)
+        .encrypt_vec(&MSG[..15])
+        .is_err());
+}
+
+#[test]
+fn aes128_cbc_rbt_roundtrip() {
+    let mut buf1 = [0u8; N];
+    let mut buf2 = [0u8; N];
+
+    let cipher = Aes128::new(&KEY.into());
+    for i in 0..MSG.len() {
+        let orig_pt = &MSG[..i];
+        let ct = &mut buf1[..i];
+        cts::CbcRbt::inner_iv_init(&cipher, &IV.into())
+            .encrypt_b2b(orig_pt, ct)
+            .unwrap();
+
+        let pt = &mut buf2[..i];
+        cts::CbcRbt::inner_iv_init(&cipher, &IV.into())
+            .decrypt_b2b(ct, pt)
+            .unwrap();
+        assert_eq!(pt, orig_pt);
+
+        cts::CbcRbt::inner_iv_init(&cipher, &IV.into())
+            .encrypt(pt)
+            .unwrap();
+        assert_eq!(pt, ct);
+
+        cts::CbcRbt::inner_iv_init(&cipher, &IV.into())
+            .decrypt(pt)
+            .unwrap();
+        assert_eq!(pt, orig_pt);
+    }
+}
+
+#[test]
+fn aes128_cbc_rbt_residual_block() {
+    use cts::cipher::BlockCipherEncrypt;
+
+    let cipher = Aes128::new(&KEY.into());
+    for i in 0..MSG.len() {
+        let full_len = i - i % 16;
+
+        // full blocks are encrypted in the CBC mode, i.e. same as CBC-CS1
+        let mut ct = MSG;
+        let ct = &mut ct[..i];
+        cts::CbcRbt::inner_iv_init(&cipher, &IV.into())
+            .encrypt(ct)
+            .unwrap();
+        if full_len != 0 {
+            let mut cbc_ct = MSG;
+            cts::CbcCs1::inner_iv_init(&cipher, &IV.into())
+                .encrypt(&mut cbc_ct[..full_len])
+                .unwrap();
+            assert_eq!(ct[..full_len], cbc_ct[..full_len]);
+        }
+
+        // residual bytes are XORed with encryption of the last ciphertext block
+        let mut keystream = match full_len {
+            0 => IV.into(),
+            _ => cipher::Block::<Aes128>::try_from(&ct[full_len - 16..full_len]).unwrap(),
+        };
+        cipher.encrypt_block(&mut keystream);
+        for j in full_len..i {
+            assert_eq!(ct[j], MSG[j] ^ keystream[j - full_len]);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#![feature(test)]
+extern crate test;
+
+use aes::Aes128;
+
+cipher::block_encryptor_bench!(
+    Key: ecb::Encryptor<Aes128>,
+    ecb_aes128_encrypt_block,
+    ecb_aes128_encrypt_blocks,
+);
+
+cipher::block_decryptor_bench!(
+    Key: ecb::Decryptor<Aes128>,
+    ecb_aes128_decrypt_block,
+    ecb_aes128_decrypt_blocks,
+);
@@ -0,0 +1,152 @@
+use cipher::{
+    crypto_common::{BlockSizes, InnerInit, InnerUser},
+    inout::InOut,
+    AlgorithmName, Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt,
+    BlockModeDecBackend, BlockModeDecClosure, BlockModeDecrypt, BlockSizeUser, ParBlocks,
+    ParBlocksSizeUser,
+};
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "zeroize")]
+use cipher::zeroize::ZeroizeOnDrop;
+
+/// ECB mode decryptor.
+#[derive(Clone)]
+pub struct Decryptor<C>
+where
+    C: BlockCipherDecrypt,
+{
+    cipher: C,
+}
+
+impl<C> BlockSizeUser for Decryptor<C>
+where
+    C: BlockCipherDecrypt,
+{
+    type BlockSize = C::BlockSize;
+}
+
+impl<C> InnerUser for Decryptor<C>
+where
+    C: BlockCipherDecrypt,
+{
+    type Inner = C;
+}
+
+impl<C> InnerInit for Decryptor<C>
+where
+    C: BlockCipherDecrypt,
+{
+    #[inline]
+    fn inner_init(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C> BlockModeDecrypt for Decryptor<C>
+where
+    C: BlockCipherDecrypt,
+{
+    fn decrypt_with_backend(&mut self, f: impl BlockModeDecClosure<BlockSize = Self::BlockSize>) {
+        struct Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeDecClosure<BlockSize = BS>,
+        {
+            f: BC,
+            _pd: PhantomData<BS>,
+        }
+
+        impl<BS, BC> BlockSizeUser for Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeDecClosure<BlockSize = BS>,
+        {
+            type BlockSize = BS;
+        }
+
+        impl<BS, BC> BlockCipherDecClosure for Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeDecClosure<BlockSize = BS>,
+        {
+            #[inline(always)]
+            fn call<B: BlockCipherDecBackend<BlockSize = Self::BlockSize>>(
+                self,
+                cipher_backend: &B,
+            ) {
+                self.f.call(&mut Backend { cipher_backend });
+            }
+        }
+
+        self.cipher.decrypt_with_backend(Closure {
+            f,
+            _pd: PhantomData,
+        })
+    }
+}
+
+impl<C> AlgorithmName for Decryptor<C>
+where
+    C: BlockCipherDecrypt + AlgorithmName,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ecb::Decryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for Decryptor<C>
+where
+    C: BlockCipherDecrypt + AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ecb::Decryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockCipherDecrypt + ZeroizeOnDrop> ZeroizeOnDrop for Decryptor<C> {}
+
+struct Backend<'a, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherDecBackend<BlockSize = BS>,
+{
+    cipher_backend: &'a BK,
+}
+
+impl<BS, BK> BlockSizeUser for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherDecBackend<BlockSize = BS>,
+{
+    type BlockSize = BS;
+}
+
+impl<BS, BK> ParBlocksSizeUser for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherDecBackend<BlockSize = BS>,
+{
+    type ParBlocksSize = BK::ParBlocksSize;
+}
+
+impl<BS, BK> BlockModeDecBackend for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherDecBackend<BlockSize = BS>,
+{
+    #[inline(always)]
+    fn decrypt_block(&mut self, block: InOut<'_, '_, Block<Self>>) {
+        self.cipher_backend.decrypt_block(block);
+    }
+
+    #[inline(always)]
+    fn decrypt_par_blocks(&mut self, blocks: InOut<'_, '_, ParBlocks<Self>>) {
+        self.cipher_backend.decrypt_par_blocks(blocks);
+    }
+}
@@ -0,0 +1,152 @@
+use cipher::{
+    crypto_common::{BlockSizes, InnerInit, InnerUser},
+    inout::InOut,
+    AlgorithmName, Block, BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt,
+    BlockModeEncBackend, BlockModeEncClosure, BlockModeEncrypt, BlockSizeUser, ParBlocks,
+    ParBlocksSizeUser,
+};
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "zeroize")]
+use cipher::zeroize::ZeroizeOnDrop;
+
+/// ECB mode encryptor.
+#[derive(Clone)]
+pub struct Encryptor<C>
+where
+    C: BlockCipherEncrypt,
+{
+    cipher: C,
+}
+
+impl<C> BlockSizeUser for Encryptor<C>
+where
+    C: BlockCipherEncrypt,
+{
+    type BlockSize = C::BlockSize;
+}
+
+impl<C> InnerUser for Encryptor<C>
+where
+    C: BlockCipherEncrypt,
+{
+    type Inner = C;
+}
+
+impl<C> InnerInit for Encryptor<C>
+where
+    C: BlockCipherEncrypt,
+{
+    #[inline]
+    fn inner_init(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C> BlockModeEncrypt for Encryptor<C>
+where
+    C: BlockCipherEncrypt,
+{
+    fn encrypt_with_backend(&mut self, f: impl BlockModeEncClosure<BlockSize = Self::BlockSize>) {
+        struct Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeEncClosure<BlockSize = BS>,
+        {
+            f: BC,
+            _pd: PhantomData<BS>,
+        }
+
+        impl<BS, BC> BlockSizeUser for Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeEncClosure<BlockSize = BS>,
+        {
+            type BlockSize = BS;
+        }
+
+        impl<BS, BC> BlockCipherEncClosure for Closure<BS, BC>
+        where
+            BS: BlockSizes,
+            BC: BlockModeEncClosure<BlockSize = BS>,
+        {
+            #[inline(always)]
+            fn call<B: BlockCipherEncBackend<BlockSize = Self::BlockSize>>(
+                self,
+                cipher_backend: &B,
+            ) {
+                self.f.call(&mut Backend { cipher_backend });
+            }
+        }
+
+        self.cipher.encrypt_with_backend(Closure {
+            f,
+            _pd: PhantomData,
+        })
+    }
+}
+
+impl<C> AlgorithmName for Encryptor<C>
+where
+    C: BlockCipherEncrypt + AlgorithmName,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ecb::Encryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for Encryptor<C>
+where
+    C: BlockCipherEncrypt + AlgorithmName,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ecb::Encryptor<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockCipherEncrypt + ZeroizeOnDrop> ZeroizeOnDrop for Encryptor<C> {}
+
+struct Backend<'a, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherEncBackend<BlockSize = BS>,
+{
+    cipher_backend: &'a BK,
+}
+
+impl<BS, BK> BlockSizeUser for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherEncBackend<BlockSize = BS>,
+{
+    type BlockSize = BS;
+}
+
+impl<BS, BK> ParBlocksSizeUser for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherEncBackend<BlockSize = BS>,
+{
+    type ParBlocksSize = BK::ParBlocksSize;
+}
+
+impl<BS, BK> BlockModeEncBackend for Backend<'_, BS, BK>
+where
+    BS: BlockSizes,
+    BK: BlockCipherEncBackend<BlockSize = BS>,
+{
+    #[inline(always)]
+    fn encrypt_block(&mut self, block: InOut<'_, '_, Block<Self>>) {
+        self.cipher_backend.encrypt_block(block);
+    }
+
+    #[inline(always)]
+    fn encrypt_par_blocks(&mut self, blocks: InOut<'_, '_, ParBlocks<Self>>) {
+        self.cipher_backend.encrypt_par_blocks(blocks);
+    }
+}
@@ -0,0 +1,70 @@
+use aes::*;
+use cipher::{
+    array::Array, inout::InOutBuf, Block, BlockCipherEncrypt, BlockModeDecrypt, BlockModeEncrypt,
+    KeyInit,
+};
+use ecb::{Decryptor, Encryptor};
+use hex_literal::hex;
+
+// Test vectors from NIST SP 800-38A, F.1.1 and F.1.2:
+// <https://csrc.nist.gov/pubs/sp/800/38/a/final>
+const KEY: [u8; 16] = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+const PLAINTEXT: [u8; 64] = hex!(
+    "6bc1bee22e409f96e93d7e117393172a"
+    "ae2d8a571e03ac9c9eb76fac45af8e51"
+    "30c81c46a35ce411e5fbc1191a0a52ef"
+    "f69f2445df4f9b17ad2b417be66c3710"
+);
+const CIPHERTEXT: [u8; 64] = hex!(
+    "3ad77bb40d7a3660a89ecaf32466ef97"
+    "f5d3d58503b9699de785895a96fdbaaf"
+    "43b1cd7f598ece23881b00e3ed030688"
+    "7b0c785e27e8ad3f8223207104725dd4"
+);
+
+#[test]
+fn aes128_ecb_enc_test() {
+    let mut buf = PLAINTEXT;
+    let (blocks, _) = InOutBuf::from(&mut buf[..]).into_chunks();
+    Encryptor::<Aes128>::new(&KEY.into()).encrypt_blocks_inout(blocks);
+    assert_eq!(buf, CIPHERTEXT);
+
+    let mut buf = PLAINTEXT;
+    let (blocks, _) = InOutBuf::from(&mut buf[..]).into_chunks();
+    Encryptor::<Aes128Enc>::new(&KEY.into()).encrypt_blocks_inout(blocks);
+    assert_eq!(buf, CIPHERTEXT);
+}
+
+#[test]
+fn aes128_ecb_dec_test() {
+    let mut buf = CIPHERTEXT;
+    let (blocks, _) = InOutBuf::from(&mut buf[..]).into_chunks();
+    Decryptor::<Aes128>::new(&KEY.into()).decrypt_blocks_inout(blocks);
+    assert_eq!(buf, PLAINTEXT);
+
+    let mut buf = CIPHERTEXT;
+    let (blocks, _) = InOutBuf::from(&mut buf[..]).into_chunks();
+    Decryptor::<Aes128Dec>::new(&KEY.into()).decrypt_blocks_inout(blocks);
+    assert_eq!(buf, PLAINTEXT);
+}
+
+// 40 blocks are more than `ParBlocksSize` of the AES backends (e.g. 9 for
+// AES-NI and 21 for ARMv8) and leave a remainder, so both the parallel and
+// the tail block paths are exercised.
+#[test]
+fn aes128_ecb_par_blocks_test() {
+    let pt: [Block<Aes128>; 40] = core::array::from_fn(|i| Array::from_fn(|j| (16 * i + j) as u8));
+
+    let cipher = Aes128::new(&KEY.into());
+    let mut expected = pt;
+    for block in expected.iter_mut() {
+        cipher.encrypt_block(block);
+    }
+
+    let mut buf = pt;
+    Encryptor::<Aes128>::new(&KEY.into()).encrypt_blocks(&mut buf);
+    assert_eq!(buf, expected);
+
+    Decryptor::<Aes128>::new(&KEY.into()).decrypt_blocks(&mut buf);
+    assert_eq!(buf, pt);
+}
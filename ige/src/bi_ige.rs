@@ -0,0 +1,280 @@
+use crate::{Decryptor, Encryptor, IgeIvSize};
+use cipher::{
+    array::{Array, ArraySize},
+    crypto_common::{IvSizeUser, KeySizeUser},
+    inout::InOutBuf,
+    typenum::{Sum, Unsigned},
+    AlgorithmName, Block, BlockCipherDecrypt, BlockCipherEncrypt, BlockModeDecrypt,
+    BlockModeEncrypt, BlockSizeUser, InnerIvInit, Iv, Key, KeyInit, KeyIvInit,
+};
+use core::{fmt, ops::Add};
+
+#[cfg(feature = "block-padding")]
+use cipher::{
+    block_padding::{Padding, UnpadError},
+    inout::PadError,
+};
+
+#[cfg(feature = "zeroize")]
+use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Bi-directional IGE (biIGE) mode.
+///
+/// Encryption first processes the message with IGE using `cipher1` and `iv1`,
+/// and then processes the result in the reverse block order with IGE using
+/// `cipher2` and `iv2`. Thus every ciphertext block depends on every plaintext
+/// block and vice versa. Decryption performs the inverse steps.
+///
+/// When initialized with [`KeyIvInit`], the key is a concatenation of the two
+/// block cipher keys and the IV is a concatenation of the two IGE IVs, each
+/// of them having the same layout as for the [`Encryptor`] and [`Decryptor`]
+/// types. This matches the key and IV arguments of OpenSSL's
+/// `AES_bi_ige_encrypt`. Note that OpenSSL uses its first key for both passes
+/// and ignores the second one, so to get compatible results the first key
+/// must be repeated twice.
+///
+/// Since processing requires two passes over data, the whole message must be
+/// provided in one call.
+#[derive(Clone)]
+pub struct BiIge<C>
+where
+    C: BlockSizeUser,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    cipher1: C,
+    cipher2: C,
+    iv1: Array<u8, IgeIvSize<C>>,
+    iv2: Array<u8, IgeIvSize<C>>,
+}
+
+impl<C> BiIge<C>
+where
+    C: BlockSizeUser,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    /// Create new biIGE mode instance from block cipher instances and IVs
+    /// for the forward (`cipher1`, `iv1`) and backward (`cipher2`, `iv2`) passes.
+    pub fn from_ciphers(
+        cipher1: C,
+        iv1: &Array<u8, IgeIvSize<C>>,
+        cipher2: C,
+        iv2: &Array<u8, IgeIvSize<C>>,
+    ) -> Self {
+        Self {
+            cipher1,
+            cipher2,
+            iv1: iv1.clone(),
+            iv2: iv2.clone(),
+        }
+    }
+}
+
+type BiIgeKeySize<C> = Sum<<C as KeySizeUser>::KeySize, <C as KeySizeUser>::KeySize>;
+type BiIgeIvSize<C> = Sum<IgeIvSize<C>, IgeIvSize<C>>;
+
+impl<C> KeySizeUser for BiIge<C>
+where
+    C: BlockSizeUser + KeySizeUser,
+    C::BlockSize: Add,
+    C::KeySize: Add,
+    IgeIvSize<C>: ArraySize,
+    BiIgeKeySize<C>: ArraySize,
+{
+    type KeySize = BiIgeKeySize<C>;
+}
+
+impl<C> IvSizeUser for BiIge<C>
+where
+    C: BlockSizeUser,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize + Add,
+    BiIgeIvSize<C>: ArraySize,
+{
+    type IvSize = BiIgeIvSize<C>;
+}
+
+impl<C> KeyIvInit for BiIge<C>
+where
+    C: BlockSizeUser + KeyInit,
+    C::BlockSize: Add,
+    C::KeySize: Add,
+    IgeIvSize<C>: ArraySize + Add,
+    BiIgeKeySize<C>: ArraySize,
+    BiIgeIvSize<C>: ArraySize,
+{
+    #[inline]
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        let key_size = C::KeySize::USIZE;
+        let iv_size = IgeIvSize::<C>::USIZE;
+        Self::from_ciphers(
+            C::new(&Array::from_fn(|i| key[i])),
+            &Array::from_fn(|i| iv[i]),
+            C::new(&Array::from_fn(|i| key[key_size + i])),
+            &Array::from_fn(|i| iv[iv_size + i]),
+        )
+    }
+}
+
+impl<C> BiIge<C>
+where
+    C: BlockCipherEncrypt,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    /// Encrypt `inout` blocks.
+    pub fn encrypt_blocks_inout(self, mut blocks: InOutBuf<'_, '_, Block<C>>) {
+        Encryptor::<&C>::inner_iv_init(&self.cipher1, &self.iv1)
+            .encrypt_blocks_inout(blocks.reborrow());
+
+        let mut enc = Encryptor::<&C>::inner_iv_init(&self.cipher2, &self.iv2);
+        for block in blocks.get_out().iter_mut().rev() {
+            enc.encrypt_block(block);
+        }
+    }
+
+    /// Encrypt blocks in-place.
+    pub fn encrypt_blocks(self, blocks: &mut [Block<C>]) {
+        self.encrypt_blocks_inout(blocks.into())
+    }
+
+    /// Encrypt blocks buffer-to-buffer.
+    ///
+    /// Returns [`NotEqualError`][cipher::inout::NotEqualError] if provided
+    /// `in_blocks` and `out_blocks` have different lengths.
+    pub fn encrypt_blocks_b2b(
+        self,
+        in_blocks: &[Block<C>],
+        out_blocks: &mut [Block<C>],
+    ) -> Result<(), cipher::inout::NotEqualError> {
+        InOutBuf::new(in_blocks, out_blocks).map(|blocks| self.encrypt_blocks_inout(blocks))
+    }
+
+    /// Pad input and encrypt. Returns resulting ciphertext slice.
+    ///
+    /// Returns [`PadError`] if length of output buffer is not sufficient.
+    #[cfg(feature = "block-padding")]
+    pub fn encrypt_padded<P: Padding<C::BlockSize>>(
+        self,
+        buf: &mut [u8],
+        msg_len: usize,
+    ) -> Result<&[u8], PadError> {
+        let ct_len = Encryptor::<&C>::inner_iv_init(&self.cipher1, &self.iv1)
+            .encrypt_padded::<P>(buf, msg_len)?
+            .len();
+
+        let buf = &mut buf[..ct_len];
+        let (blocks, _) = InOutBuf::from(&mut *buf).into_chunks();
+        let mut enc = Encryptor::<&C>::inner_iv_init(&self.cipher2, &self.iv2);
+        for block in blocks.into_out().iter_mut().rev() {
+            enc.encrypt_block(block);
+        }
+        Ok(buf)
+    }
+}
+
+impl<C> BiIge<C>
+where
+    C: BlockCipherDecrypt,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    /// Decrypt `inout` blocks.
+    pub fn decrypt_blocks_inout(self, mut blocks: InOutBuf<'_, '_, Block<C>>) {
+        let mut dec = Decryptor::<&C>::inner_iv_init(&self.cipher2, &self.iv2);
+        for i in (0..blocks.len()).rev() {
+            dec.decrypt_block_inout(blocks.get(i));
+        }
+
+        Decryptor::<&C>::inner_iv_init(&self.cipher1, &self.iv1).decrypt_blocks(blocks.into_out());
+    }
+
+    /// Decrypt blocks in-place.
+    pub fn decrypt_blocks(self, blocks: &mut [Block<C>]) {
+        self.decrypt_blocks_inout(blocks.into())
+    }
+
+    /// Decrypt blocks buffer-to-buffer.
+    ///
+    /// Returns [`NotEqualError`][cipher::inout::NotEqualError] if provided
+    /// `in_blocks` and `out_blocks` have different lengths.
+    pub fn decrypt_blocks_b2b(
+        self,
+        in_blocks: &[Block<C>],
+        out_blocks: &mut [Block<C>],
+    ) -> Result<(), cipher::inout::NotEqualError> {
+        InOutBuf::new(in_blocks, out_blocks).map(|blocks| self.decrypt_blocks_inout(blocks))
+    }
+
+    /// Decrypt input and unpad it. Returns resulting plaintext slice.
+    ///
+    /// Returns [`UnpadError`] if padding is malformed or if input length is
+    /// not multiple of block size.
+    #[cfg(feature = "block-padding")]
+    pub fn decrypt_padded<P: Padding<C::BlockSize>>(
+        self,
+        buf: &mut [u8],
+    ) -> Result<&[u8], UnpadError> {
+        let (blocks, tail) = InOutBuf::from(&mut *buf).into_chunks();
+        if !tail.is_empty() {
+            return Err(UnpadError);
+        }
+        let mut dec = Decryptor::<&C>::inner_iv_init(&self.cipher2, &self.iv2);
+        for block in blocks.into_out().iter_mut().rev() {
+            dec.decrypt_block(block);
+        }
+
+        Decryptor::<&C>::inner_iv_init(&self.cipher1, &self.iv1).decrypt_padded::<P>(buf)
+    }
+}
+
+impl<C> AlgorithmName for BiIge<C>
+where
+    C: BlockSizeUser + AlgorithmName,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ige::BiIge<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str(">")
+    }
+}
+
+impl<C> fmt::Debug for BiIge<C>
+where
+    C: BlockSizeUser + AlgorithmName,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ige::BiIge<")?;
+        <C as AlgorithmName>::write_alg_name(f)?;
+        f.write_str("> { ... }")
+    }
+}
+
+impl<C> Drop for BiIge<C>
+where
+    C: BlockSizeUser,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        {
+            self.iv1.zeroize();
+            self.iv2.zeroize();
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> ZeroizeOnDrop for BiIge<C>
+where
+    C: BlockSizeUser + ZeroizeOnDrop,
+    C::BlockSize: Add,
+    IgeIvSize<C>: ArraySize,
+{
+}
@@ -85,6 +85,10 @@
 //! assert_eq!(res[..], plaintext[..]);
 //! # }
 //! ```
+//!
+//! The bi-directional IGE variant (biIGE), in which every ciphertext block
+//! depends on the whole plaintext, is available as [`BiIge`].
+//!
 //! [1]: https://www.links.org/files/openssl-ige.pdf
 
 #![no_std]
@@ -96,9 +100,11 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(missing_debug_implementations, missing_docs, rust_2018_idioms)]
 
+mod bi_ige;
 mod decrypt;
 mod encrypt;
 
+pub use bi_ige::BiIge;
 pub use cipher;
 pub use decrypt::Decryptor;
 pub use encrypt::Encryptor;
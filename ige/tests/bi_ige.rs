@@ -0,0 +1,172 @@
+use aes::Aes128;
+use cipher::{array::Array, consts::U16, inout::InOutBuf, BlockModeEncrypt, KeyInit, KeyIvInit};
+use hex_literal::hex;
+use ige::{BiIge, Encryptor};
+
+const KEY1: [u8; 16] = [0x42; 16];
+const KEY2: [u8; 16] = [0x24; 16];
+const IV1: [u8; 32] = [0x13; 32];
+const IV2: [u8; 32] = [0x37; 32];
+
+fn bi_ige() -> BiIge<Aes128> {
+    BiIge::from_ciphers(
+        Aes128::new(&KEY1.into()),
+        &IV1.into(),
+        Aes128::new(&KEY2.into()),
+        &IV2.into(),
+    )
+}
+
+fn plaintext() -> [Array<u8, U16>; 4] {
+    core::array::from_fn(|i| Array::from([i as u8; 16]))
+}
+
+#[test]
+fn aes128_bi_ige_roundtrip() {
+    let pt = plaintext();
+    let mut buf = pt;
+    bi_ige().encrypt_blocks(&mut buf);
+    assert_ne!(buf, pt);
+    bi_ige().decrypt_blocks(&mut buf);
+    assert_eq!(buf, pt);
+
+    let mut ct = pt;
+    bi_ige().encrypt_blocks_b2b(&pt, &mut ct).unwrap();
+    let mut res = pt;
+    bi_ige().decrypt_blocks_b2b(&ct, &mut res).unwrap();
+    assert_eq!(res, pt);
+}
+
+#[test]
+fn aes128_bi_ige_structure() {
+    let pt = plaintext();
+
+    let mut expected = pt;
+    Encryptor::<Aes128>::new(&KEY1.into(), &IV1.into()).encrypt_blocks(&mut expected);
+    expected.reverse();
+    Encryptor::<Aes128>::new(&KEY2.into(), &IV2.into()).encrypt_blocks(&mut expected);
+    expected.reverse();
+
+    let mut buf = pt;
+    bi_ige().encrypt_blocks(&mut buf);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn aes128_bi_ige_garbles_whole_message() {
+    let pt = plaintext();
+    let mut ct = pt;
+    bi_ige().encrypt_blocks(&mut ct);
+
+    for i in 0..ct.len() {
+        let mut buf = ct;
+        buf[i][0] ^= 1;
+        bi_ige().decrypt_blocks(&mut buf);
+        for (a, b) in buf.iter().zip(pt.iter()) {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+/// Encrypts `pt` with `key` and `iv` in OpenSSL's `AES_bi_ige_encrypt` layout,
+/// checks the result against `ct` and decrypts it back.
+///
+/// OpenSSL ignores its second key, so `key` is the first key repeated twice.
+fn check_openssl_vector(key: &[u8], iv: &[u8], pt: &[u8], ct: &[u8]) {
+    let mut buf = [0u8; 256];
+    let buf = &mut buf[..pt.len()];
+    buf.copy_from_slice(pt);
+    let (blocks, _) = InOutBuf::from(&mut *buf).into_chunks();
+    BiIge::<Aes128>::new_from_slices(key, iv)
+        .unwrap()
+        .encrypt_blocks_inout(blocks);
+    assert_eq!(buf, ct);
+
+    let (blocks, _) = InOutBuf::from(&mut *buf).into_chunks();
+    BiIge::<Aes128>::new_from_slices(key, iv)
+        .unwrap()
+        .decrypt_blocks_inout(blocks);
+    assert_eq!(buf, pt);
+}
+
+// First test vector from OpenSSL's `test/igetest.c` (`bi_ige_test_vectors`),
+// its second key `101112131415161718191a1b1c1d1e1f` is not used by OpenSSL
+#[test]
+fn aes128_bi_ige_openssl_igetest() {
+    check_openssl_vector(
+        &hex!(
+            "000102030405060708090a0b0c0d0e0f"
+            "000102030405060708090a0b0c0d0e0f"
+        ),
+        &hex!(
+            "000102030405060708090a0b0c0d0e0f"
+            "101112131415161718191a1b1c1d1e1f"
+            "202122232425262728292a2b2c2d2e2f"
+            "303132333435363738393a3b3c3d3e3f"
+        ),
+        &[0u8; 32],
+        &hex!(
+            "14406faea279f2561f86eb3b7dff53dc"
+            "4e270c03de7ce5166a9c20339d33fe12"
+        ),
+    );
+}
+
+// Test vector generated with OpenSSL's `AES_bi_ige_encrypt`
+#[test]
+fn aes128_bi_ige_openssl_long() {
+    check_openssl_vector(
+        &hex!(
+            "808182838485868788898a8b8c8d8e8f"
+            "808182838485868788898a8b8c8d8e8f"
+        ),
+        &hex!(
+            "a0a1a2a3a4a5a6a7a8a9aaabacadaeaf"
+            "b0b1b2b3b4b5b6b7b8b9babbbcbdbebf"
+            "c0c1c2c3c4c5c6c7c8c9cacbcccdcecf"
+            "d0d1d2d3d4d5d6d7d8d9dadbdcdddedf"
+        ),
+        &hex!(
+            "030a11181f262d343b424950575e656c"
+            "737a81888f969da4abb2b9c0c7ced5dc"
+            "e3eaf1f8ff060d141b222930373e454c"
+            "535a61686f767d848b9299a0a7aeb5bc"
+            "c3cad1d8dfe6edf4fb020910171e252c"
+        ),
+        &hex!(
+            "d1e428ed13d26fbea342565138ac3cd8"
+            "4c38eedb73ba3d622eaf7363170aada1"
+            "cdc7c7358df06241a99f01e133d3c0e4"
+            "aba7446447a3b89c3a3eab2eb6d5a708"
+            "4d81fe3bab0af95e2042ce250d45f173"
+        ),
+    );
+}
+
+#[cfg(feature = "block-padding")]
+#[test]
+fn aes128_bi_ige_padded_roundtrip() {
+    use cipher::block_padding::Pkcs7;
+
+    let key = [0x42; 32];
+    let iv = [0x24; 64];
+    let msg = b"hello world! this is my plaintext.";
+
+    let mut buf = [0u8; 48];
+    buf[..msg.len()].copy_from_slice(msg);
+    let ct = BiIge::<Aes128>::new(&key.into(), &iv.into())
+        .encrypt_padded::<Pkcs7>(&mut buf, msg.len())
+        .unwrap();
+    assert_eq!(ct.len(), 48);
+
+    let pt = BiIge::<Aes128>::new(&key.into(), &iv.into())
+        .decrypt_padded::<Pkcs7>(&mut buf)
+        .unwrap();
+    assert_eq!(pt, msg);
+
+    let mut short = [0u8; 40];
+    short[..msg.len()].copy_from_slice(msg);
+    assert!(BiIge::<Aes128>::new(&key.into(), &iv.into())
+        .encrypt_padded::<Pkcs7>(&mut short, msg.len())
+        .is_err());
+}